@@ -1,5 +1,139 @@
-use crate::{BlockDevice, Controller, Directory, Error, File, Mode, TimeSource, Volume, VolumeIdx};
+use crate::{
+    BlockDevice, Controller, DirEntry, Directory, Error, File, Mode, TimeSource, Volume, VolumeIdx,
+};
 use core::cell::{RefCell, RefMut};
+use core::fmt::Write as _;
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A position to seek to within a [`FileHandle`], mirroring
+/// `std::io::SeekFrom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekFrom {
+    /// An absolute byte offset from the start of the file.
+    Start(u32),
+    /// An offset relative to the current position.
+    Current(i32),
+    /// An offset relative to the end of the file.
+    End(i32),
+}
+
+/// Resolves `pos` against a file's `current_offset` and `length`, erroring
+/// (rather than clamping) if the result would fall outside `[0, length]`.
+fn resolve_seek<E: core::fmt::Debug>(
+    current_offset: u32,
+    length: u32,
+    pos: SeekFrom,
+) -> Result<u32, Error<E>> {
+    let length = length as i64;
+    let target = match pos {
+        SeekFrom::Start(offset) => offset as i64,
+        SeekFrom::Current(delta) => current_offset as i64 + delta as i64,
+        SeekFrom::End(delta) => length + delta as i64,
+    };
+    if target < 0 || target > length {
+        return Err(Error::InvalidOffset);
+    }
+    Ok(target as u32)
+}
+
+/// Bytes per block on the SD/MMC devices this crate targets.
+const BLOCK_SIZE_BYTES: u32 = 512;
+
+/// Whether seeking to `target_offset` would land outside the span covered
+/// by the FAT chain's current cluster, which starts at `cluster_start_offset`
+/// and is `cluster_size_bytes` long.
+///
+/// `read`/`write` advance `File::current_cluster` forward from its stored
+/// `(start_offset, cluster)` as they consume bytes, and compute
+/// `current_offset - current_cluster.0` to find the position within that
+/// cluster. A seek that lands before the cluster's start (backward) or at or
+/// past its end (forward, e.g. jumping several clusters ahead) would make
+/// that computation underflow or index into the wrong cluster's data, so
+/// the chain walk must be reset back to the file's `starting_cluster` first.
+fn seek_crosses_cluster_bounds(
+    cluster_start_offset: u32,
+    cluster_size_bytes: u32,
+    target_offset: u32,
+) -> bool {
+    target_offset < cluster_start_offset
+        || target_offset >= cluster_start_offset.saturating_add(cluster_size_bytes)
+}
+
+/// Upper bound on how deep a [`DirectoryHandle::walk`] / `walk_with` may
+/// recurse. Traversal uses an explicit work stack rather than function
+/// recursion (this handle layer targets `no_std` callers with small
+/// stacks), so the bound is enforced by the stack's fixed capacity instead
+/// of the call stack.
+const MAX_WALK_DEPTH: usize = 16;
+
+/// Renders a short (8.3) file name into a small on-stack buffer, for the
+/// rare cases (directory descent) where we need it as a plain `&str`.
+struct ShortNameBuf {
+    bytes: [u8; 16],
+    len: usize,
+}
+impl ShortNameBuf {
+    fn render(entry: &DirEntry) -> Self {
+        let mut buf = Self {
+            bytes: [0; 16],
+            len: 0,
+        };
+        let _ = write!(buf, "{}", entry.name);
+        buf
+    }
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or("")
+    }
+}
+impl core::fmt::Write for ShortNameBuf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let bytes = s.as_bytes();
+        let end = (self.len + bytes.len()).min(self.bytes.len());
+        let n = end - self.len;
+        self.bytes[self.len..end].copy_from_slice(&bytes[..n]);
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Splits a `/`-separated path into its normalized components, dropping
+/// empty segments (e.g. from a leading, trailing, or doubled `/`) and
+/// current-directory (`.`) segments.
+fn path_components(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+}
+
+/// Fetches the `index`-th entry of `directory` by re-scanning it from the
+/// start. Used by `ReadDir` and the directory-walk machinery below to keep
+/// each lookup stateless, at the cost of making a full scan `O(n)` — so a
+/// single `ReadDir`/`Walk` pass over a directory of `n` entries is `O(n^2)`,
+/// and `walk`/`check` compound that per level of depth. Fine for the small
+/// directories this `no_std` layer targets; revisit (e.g. buffering one
+/// directory's entries per frame) if that stops being true.
+fn dir_entry_at<'a, C>(
+    directory: &DirectoryHandle<'a, C>,
+    index: usize,
+) -> Result<Option<DirEntry>, Error<C::Error>>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    let mut count = 0;
+    let mut found = None;
+    directory.controller.iterate_dir(
+        directory.volume,
+        directory.directory.as_ref().unwrap(),
+        |entry| {
+            if count == index {
+                found = Some(entry.clone());
+            }
+            count += 1;
+        },
+    )?;
+    Ok(found)
+}
 
 pub struct ControllerHandle<D, T>
 where
@@ -33,6 +167,12 @@ pub trait ControllerTrait: Sized {
         &'a self,
         volume: &'a VolumeHandle<'a, Self>,
     ) -> Result<DirectoryHandle<'a, Self>, Error<Self::Error>>;
+    fn open_dir<'a>(
+        &'a self,
+        volume: &'a VolumeHandle<'a, Self>,
+        parent: &Directory,
+        name: &str,
+    ) -> Result<DirectoryHandle<'a, Self>, Error<Self::Error>>;
     fn close_directory(
         &self,
         volume: &VolumeHandle<Self>,
@@ -59,6 +199,30 @@ pub trait ControllerTrait: Sized {
         file: &mut File,
         data: &[u8],
     ) -> Result<usize, Error<Self::Error>>;
+    fn seek(
+        &self,
+        volume: &VolumeHandle<Self>,
+        file: &mut File,
+        pos: SeekFrom,
+    ) -> Result<u32, Error<Self::Error>>;
+    fn iterate_dir<F>(
+        &self,
+        volume: &VolumeHandle<Self>,
+        directory: &Directory,
+        func: F,
+    ) -> Result<(), Error<Self::Error>>
+    where
+        F: FnMut(&DirEntry);
+    /// Returns the cluster that follows `cluster` in the FAT chain, or
+    /// `None` if `cluster` is the last cluster of its chain (its FAT entry
+    /// is an end-of-chain marker). Used by
+    /// [`check`](self::check) to walk cluster chains without mutating the
+    /// device.
+    fn next_cluster(
+        &self,
+        volume: &VolumeHandle<Self>,
+        cluster: u32,
+    ) -> Result<Option<u32>, Error<Self::Error>>;
 
     fn write_root_file(
         &self,
@@ -72,6 +236,56 @@ pub trait ControllerTrait: Sized {
         let mut file = root.file(name, mode)?;
         file.write(data)
     }
+
+    /// Opens `name` in `directory`, runs `f`, then explicitly closes the
+    /// file and surfaces any close error.
+    ///
+    /// Unlike the `Drop`-based path, a failing close (e.g. a FAT update
+    /// failure while flushing) is reported to the caller instead of being
+    /// logged and swallowed. If `f` itself returns an error, that error
+    /// takes priority; the file is still closed, but the close result is
+    /// discarded in that case since the caller already has a failure to
+    /// act on.
+    fn with_file<'a, R, F>(
+        &'a self,
+        volume: &'a VolumeHandle<'a, Self>,
+        directory: &Directory,
+        name: &str,
+        mode: Mode,
+        f: F,
+    ) -> Result<R, Error<Self::Error>>
+    where
+        F: FnOnce(&mut FileHandle<'a, Self>) -> Result<R, Error<Self::Error>>,
+    {
+        let mut handle = self.file(volume, directory, name, mode)?;
+        let result = f(&mut handle);
+        let file = handle.file.take().expect("file not yet closed");
+        let close_result = self.close_file(volume, file);
+        match result {
+            Ok(value) => close_result.map(|()| value),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Opens the root directory of `volume`, runs `f`, then explicitly
+    /// closes the directory and surfaces any close error. See `with_file`.
+    fn with_root<'a, R, F>(
+        &'a self,
+        volume: &'a VolumeHandle<'a, Self>,
+        f: F,
+    ) -> Result<R, Error<Self::Error>>
+    where
+        F: FnOnce(&mut DirectoryHandle<'a, Self>) -> Result<R, Error<Self::Error>>,
+    {
+        let mut handle = self.root(volume)?;
+        let result = f(&mut handle);
+        let directory = handle.directory.take().expect("directory not yet closed");
+        let close_result = self.close_directory(volume, directory);
+        match result {
+            Ok(value) => close_result.map(|()| value),
+            Err(e) => Err(e),
+        }
+    }
 }
 impl<D, T> ControllerTrait for ControllerHandle<D, T>
 where
@@ -101,6 +315,21 @@ where
             directory: Some(directory),
         })
     }
+    fn open_dir<'a>(
+        &'a self,
+        volume: &'a VolumeHandle<'a, Self>,
+        parent: &Directory,
+        name: &str,
+    ) -> Result<DirectoryHandle<'a, Self>, Error<Self::Error>> {
+        let mut controller = self.controller()?;
+        let volume_ref = volume.volume()?;
+        let directory = controller.open_dir(&volume_ref, parent, name)?;
+        Ok(DirectoryHandle {
+            controller: &self,
+            volume: &volume,
+            directory: Some(directory),
+        })
+    }
     fn close_directory(
         &self,
         volume: &VolumeHandle<Self>,
@@ -156,6 +385,49 @@ where
         let mut volume = volume.volume()?;
         controller.write(&mut volume, file, data)
     }
+    fn seek(
+        &self,
+        volume: &VolumeHandle<Self>,
+        file: &mut File,
+        pos: SeekFrom,
+    ) -> Result<u32, Error<Self::Error>> {
+        // Repositioning only touches in-memory `File` state, but still
+        // goes through the controller borrow so it can't race a
+        // concurrent read/write on the same file.
+        let _controller = self.controller()?;
+        let volume_ref = volume.volume()?;
+        let cluster_size_bytes = match &volume_ref.volume_type {
+            crate::VolumeType::Fat(fat) => fat.blocks_per_cluster as u32 * BLOCK_SIZE_BYTES,
+        };
+        let offset = resolve_seek(file.current_offset, file.length, pos)?;
+        if seek_crosses_cluster_bounds(file.current_cluster.0, cluster_size_bytes, offset) {
+            file.current_cluster = (0, file.starting_cluster);
+        }
+        file.current_offset = offset;
+        Ok(offset)
+    }
+    fn iterate_dir<F>(
+        &self,
+        volume: &VolumeHandle<Self>,
+        directory: &Directory,
+        func: F,
+    ) -> Result<(), Error<Self::Error>>
+    where
+        F: FnMut(&DirEntry),
+    {
+        let mut controller = self.controller()?;
+        let volume = volume.volume()?;
+        controller.iterate_dir(&volume, directory, func)
+    }
+    fn next_cluster(
+        &self,
+        volume: &VolumeHandle<Self>,
+        cluster: u32,
+    ) -> Result<Option<u32>, Error<Self::Error>> {
+        let mut controller = self.controller()?;
+        let volume = volume.volume()?;
+        controller.next_cluster(&volume, cluster)
+    }
 }
 
 pub struct VolumeHandle<'a, C>
@@ -175,8 +447,22 @@ where
         self.volume.try_borrow_mut().map_err(|_| Error::VolumeInUse)
     }
 
-    pub fn root(&self) -> Result<DirectoryHandle<C>, Error<C::Error>> {
-        self.controller.root(&self)
+    // `&'a self` (rather than the usual elided `&self`) so the returned
+    // handle's lifetime is the struct's own `'a`, not the ephemeral borrow
+    // of this call: `DirectoryHandle`/`FileHandle` are built from `self`
+    // directly (not a pre-typed `'a` field), so an elided receiver
+    // lifetime would cap the result at that shorter borrow instead.
+    pub fn root(&'a self) -> Result<DirectoryHandle<'a, C>, Error<C::Error>> {
+        self.controller.root(self)
+    }
+
+    /// Opens a file at `path`, walking through any intermediate directories.
+    ///
+    /// `path` is split on `/`; empty segments and `.` are ignored, so
+    /// `"/logs/2024/app.txt"`, `"logs/2024/app.txt"` and
+    /// `"./logs//2024/app.txt"` are all equivalent.
+    pub fn open_path(&'a self, path: &str, mode: Mode) -> Result<FileHandle<'a, C>, Error<C::Error>> {
+        self.root()?.open_path(path, mode)
     }
     pub fn num_blocks(&self) -> Result<u32, Error<C::Error>> {
         let volume = self.volume()?;
@@ -226,6 +512,258 @@ where
         self.controller
             .file(self.volume, &self.directory.as_ref().unwrap(), name, mode)
     }
+
+    /// Opens a file at `path`, relative to this directory, walking through
+    /// any intermediate directories.
+    ///
+    /// `path` is split on `/`; empty segments and `.` are ignored, so
+    /// `"logs/2024/app.txt"` and `"./logs//2024/app.txt"` are equivalent.
+    /// Each intermediate directory is closed as soon as the next component
+    /// has been opened from it, so at most two extra controller directory
+    /// slots (a parent and the child being opened from it) are held at once.
+    pub fn open_path(&self, path: &str, mode: Mode) -> Result<FileHandle<'a, C>, Error<C::Error>> {
+        let mut components = path_components(path).peekable();
+        let mut intermediate: Option<DirectoryHandle<'a, C>> = None;
+        loop {
+            let name = components.next().ok_or(Error::FileNotFound)?;
+            let current = intermediate.as_ref().unwrap_or(self);
+            if components.peek().is_none() {
+                return self.controller.file(
+                    current.volume,
+                    current.directory.as_ref().unwrap(),
+                    name,
+                    mode,
+                );
+            }
+            let child = self.controller.open_dir(
+                current.volume,
+                current.directory.as_ref().unwrap(),
+                name,
+            )?;
+            // Dropping the previous `intermediate` here closes it, so only
+            // the newly opened `child` stays open as we descend further.
+            intermediate = Some(child);
+        }
+    }
+
+    /// Returns a lazy iterator over the entries of this directory.
+    ///
+    /// Each call to `next()` re-opens the controller's `RefCell` just long
+    /// enough to copy out a single `DirEntry`, so the borrow is never held
+    /// across user code between entries.
+    pub fn iter(&self) -> ReadDir<'_, 'a, C> {
+        ReadDir {
+            directory: self,
+            index: 0,
+        }
+    }
+
+    /// Returns a depth-first iterator over this directory and every
+    /// subdirectory beneath it, yielding `(depth, DirEntry)` pairs with
+    /// `depth` relative to `self` (its immediate children are depth `0`).
+    ///
+    /// Unlike `walk_with`, this iterator always descends into every
+    /// directory it encounters; use `walk_with` if you need to prune
+    /// subtrees. Traversal opens and closes exactly one child directory at
+    /// a time as it descends and backtracks, using an explicit work stack
+    /// bounded by `MAX_WALK_DEPTH` rather than recursion.
+    pub fn walk(&self) -> Walk<'_, 'a, C> {
+        Walk {
+            root: self,
+            root_index: 0,
+            frames: core::array::from_fn(|_| None),
+            depth: 0,
+        }
+    }
+
+    /// Depth-first walks this directory and every subdirectory beneath it,
+    /// calling `visitor(depth, entry)` for each entry encountered.
+    ///
+    /// Returning [`ControlFlow::Break`] from `visitor` prunes that entry:
+    /// if it is a directory, its contents are skipped entirely. This lets
+    /// callers (e.g. a `du`-style size accumulator) stop descending into
+    /// subtrees they aren't interested in without ever materialising a
+    /// full listing.
+    pub fn walk_with<F>(&self, mut visitor: F) -> Result<(), Error<C::Error>>
+    where
+        F: FnMut(usize, &DirEntry) -> ControlFlow<()>,
+    {
+        // `frames[i]` is the open subdirectory at depth `i + 1` (depth `0`
+        // is `self` and needs no frame of its own) together with the
+        // index of the next entry to read from it.
+        let mut frames: [Option<WalkFrame<'a, C>>; MAX_WALK_DEPTH] = core::array::from_fn(|_| None);
+        let mut root_index = 0usize;
+        let mut depth = 0usize;
+        loop {
+            match walk_step(self, &mut root_index, &mut frames, &mut depth, &mut visitor) {
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => return Err(e),
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+struct WalkFrame<'a, C>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    directory: DirectoryHandle<'a, C>,
+    index: usize,
+}
+
+/// `.` and `..` entries (and non-directories) aren't descended into.
+fn is_walkable_directory(entry: &DirEntry) -> bool {
+    if !entry.attributes.is_directory() {
+        return false;
+    }
+    let name = ShortNameBuf::render(entry);
+    name.as_str() != "." && name.as_str() != ".."
+}
+
+/// Drives one bounded-depth step of a [`DirectoryHandle::walk`] /
+/// `walk_with` traversal: finds the next entry at `(root_index, frames,
+/// depth)`, backing up to the parent directory if the current one is
+/// exhausted, and descending into it if it's a walkable directory that
+/// `visitor` didn't prune. Shared by both so a correctness fix (like the
+/// borrow restructuring below) only has to be made once.
+///
+/// Returns `None` once the whole traversal (rooted at `root`) is
+/// exhausted, or `Some` with the entry just processed (or the error that
+/// stopped the walk).
+fn walk_step<'a, C, F>(
+    root: &DirectoryHandle<'a, C>,
+    root_index: &mut usize,
+    frames: &mut [Option<WalkFrame<'a, C>>; MAX_WALK_DEPTH],
+    depth: &mut usize,
+    mut visitor: F,
+) -> Option<Result<(usize, DirEntry), Error<C::Error>>>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+    F: FnMut(usize, &DirEntry) -> ControlFlow<()>,
+{
+    loop {
+        let current: &DirectoryHandle<'a, C> = if *depth == 0 {
+            root
+        } else {
+            &frames[*depth - 1].as_ref().unwrap().directory
+        };
+        let index = if *depth == 0 {
+            *root_index
+        } else {
+            frames[*depth - 1].as_ref().unwrap().index
+        };
+        match dir_entry_at(current, index) {
+            Err(e) => return Some(Err(e)),
+            Ok(None) => {
+                // Exhausted this directory; back up to its parent.
+                if *depth == 0 {
+                    return None;
+                }
+                frames[*depth - 1] = None;
+                *depth -= 1;
+                continue;
+            }
+            Ok(Some(entry)) => {
+                if *depth == 0 {
+                    *root_index += 1;
+                } else {
+                    frames[*depth - 1].as_mut().unwrap().index += 1;
+                }
+                let this_depth = *depth;
+                if let ControlFlow::Break(()) = visitor(this_depth, &entry) {
+                    continue;
+                }
+                if is_walkable_directory(&entry) {
+                    if *depth >= MAX_WALK_DEPTH {
+                        return Some(Err(Error::DirectoryDepthExceeded));
+                    }
+                    let name = ShortNameBuf::render(&entry);
+                    // Re-derive `current` here rather than reusing the one
+                    // from the top of the loop: that one borrows
+                    // `frames[depth - 1]`, which the index bump above
+                    // already mutated, so the two borrows can't overlap.
+                    let current: &DirectoryHandle<'a, C> = if this_depth == 0 {
+                        root
+                    } else {
+                        &frames[this_depth - 1].as_ref().unwrap().directory
+                    };
+                    let child = match root.controller.open_dir(
+                        current.volume,
+                        current.directory.as_ref().unwrap(),
+                        name.as_str(),
+                    ) {
+                        Ok(child) => child,
+                        Err(e) => return Some(Err(e)),
+                    };
+                    frames[*depth] = Some(WalkFrame {
+                        directory: child,
+                        index: 0,
+                    });
+                    *depth += 1;
+                }
+                return Some(Ok((this_depth, entry)));
+            }
+        }
+    }
+}
+
+pub struct Walk<'b, 'a, C>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    root: &'b DirectoryHandle<'a, C>,
+    root_index: usize,
+    frames: [Option<WalkFrame<'a, C>>; MAX_WALK_DEPTH],
+    depth: usize,
+}
+impl<'b, 'a, C> Iterator for Walk<'b, 'a, C>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    type Item = Result<(usize, DirEntry), Error<C::Error>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        walk_step(
+            self.root,
+            &mut self.root_index,
+            &mut self.frames,
+            &mut self.depth,
+            |_, _| ControlFlow::Continue(()),
+        )
+    }
+}
+pub struct ReadDir<'b, 'a, C>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    directory: &'b DirectoryHandle<'a, C>,
+    index: usize,
+}
+impl<'b, 'a, C> Iterator for ReadDir<'b, 'a, C>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    type Item = Result<DirEntry, Error<C::Error>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match dir_entry_at(self.directory, self.index) {
+            Ok(Some(entry)) => {
+                self.index += 1;
+                Some(Ok(entry))
+            }
+            Ok(None) => None,
+            // A lookup failure (e.g. a device read error, or the
+            // controller's `RefCell` already being borrowed) is distinct
+            // from end-of-directory; surface it instead of silently
+            // truncating the listing.
+            Err(e) => Some(Err(e)),
+        }
+    }
 }
 impl<'a, C> Drop for DirectoryHandle<'a, C>
 where
@@ -233,11 +771,12 @@ where
     <C as ControllerTrait>::Error: core::fmt::Debug,
 {
     fn drop(&mut self) {
-        if let Err(e) = self
-            .controller
-            .close_directory(self.volume, self.directory.take().unwrap())
-        {
-            log::info!("Error dropping FileHandle: {:?}", e);
+        // `with_root` already closes and takes `directory` explicitly; only
+        // close here if that didn't happen.
+        if let Some(directory) = self.directory.take() {
+            if let Err(e) = self.controller.close_directory(self.volume, directory) {
+                log::info!("Error dropping DirectoryHandle: {:?}", e);
+            }
         }
     }
 }
@@ -267,6 +806,28 @@ where
     pub fn size(&self) -> u32 {
         self.file.as_ref().unwrap().length
     }
+
+    /// Moves the file's current position to `pos`, returning the new
+    /// absolute offset. Errors if the target position would fall outside
+    /// `[0, size()]`.
+    pub fn seek(&mut self, pos: SeekFrom) -> Result<u32, Error<C::Error>> {
+        self.controller
+            .seek(self.volume, self.file.as_mut().unwrap(), pos)
+    }
+
+    /// Seeks to `pos`, then reads into `buffer`, returning the number of
+    /// bytes read.
+    pub fn read_at(&mut self, pos: SeekFrom, buffer: &mut [u8]) -> Result<usize, Error<C::Error>> {
+        self.seek(pos)?;
+        self.read(buffer)
+    }
+
+    /// Seeks to `pos`, then writes `data`, returning the number of bytes
+    /// written.
+    pub fn write_at(&mut self, pos: SeekFrom, data: &[u8]) -> Result<usize, Error<C::Error>> {
+        self.seek(pos)?;
+        self.write(data)
+    }
 }
 impl<'a, C> Drop for FileHandle<'a, C>
 where
@@ -274,11 +835,585 @@ where
     <C as ControllerTrait>::Error: core::fmt::Debug,
 {
     fn drop(&mut self) {
-        if let Err(e) = self
-            .controller
-            .close_file(self.volume, self.file.take().unwrap())
-        {
-            log::info!("Error dropping FileHandle: {:?}", e);
+        // `with_file` already closes and takes `file` explicitly; only
+        // close here if that didn't happen.
+        if let Some(file) = self.file.take() {
+            if let Err(e) = self.controller.close_file(self.volume, file) {
+                log::info!("Error dropping FileHandle: {:?}", e);
+            }
+        }
+    }
+}
+
+/// Folds every handle-layer error into a caller-supplied error type `U`,
+/// borrowing the "trappable error" pattern from wasmtime's bindgen (where
+/// generated code calls a user-provided conversion trait to turn the ABI
+/// error into the app's own type).
+///
+/// Instead of repeating `.map_err(...)` at every call site, define one
+/// error type, implement `From<Error<C::Error>>` for it, and wrap the
+/// controller in a [`Trapped`] to get `?`-friendly propagation across the
+/// handle API:
+///
+/// ```ignore
+/// enum AppError { Fs(Error<MyDeviceError>), /* ... */ }
+/// impl From<Error<MyDeviceError>> for AppError {
+///     fn from(e: Error<MyDeviceError>) -> Self { AppError::Fs(e) }
+/// }
+/// let trapped: Trapped<_, AppError> = Trapped::new(&controller);
+/// let volume = trapped.volume(0)?;
+/// let mut file = volume.open_path("/logs/app.txt", Mode::ReadOnly)?;
+/// ```
+pub struct Trapped<'a, C, U> {
+    controller: &'a C,
+    _error: PhantomData<U>,
+}
+impl<'a, C, U> Trapped<'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+    U: From<Error<C::Error>>,
+{
+    pub fn new(controller: &'a C) -> Self {
+        Self {
+            controller,
+            _error: PhantomData,
+        }
+    }
+    pub fn volume(&self, index: usize) -> Result<TrappedVolume<'a, C, U>, U> {
+        let volume = self.controller.volume(index)?;
+        Ok(TrappedVolume {
+            volume,
+            _error: PhantomData,
+        })
+    }
+}
+
+/// A [`VolumeHandle`] whose errors are folded into `U`. See [`Trapped`].
+pub struct TrappedVolume<'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    volume: VolumeHandle<'a, C>,
+    _error: PhantomData<U>,
+}
+impl<'a, C, U> TrappedVolume<'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+    U: From<Error<C::Error>>,
+{
+    // `&'a self`: `VolumeHandle::root`/`open_path` now require `&'a self`
+    // too, so `self.volume` (an owned field, not a `&'a` reference) must
+    // be borrowed for the full `'a` here to satisfy them.
+    pub fn root(&'a self) -> Result<TrappedDirectory<'a, C, U>, U> {
+        let directory = self.volume.root()?;
+        Ok(TrappedDirectory {
+            directory,
+            _error: PhantomData,
+        })
+    }
+    pub fn open_path(&'a self, path: &str, mode: Mode) -> Result<TrappedFile<'a, C, U>, U> {
+        let file = self.volume.open_path(path, mode)?;
+        Ok(TrappedFile {
+            file,
+            _error: PhantomData,
+        })
+    }
+    /// Validates this volume; see [`VolumeHandle::check`].
+    #[cfg(feature = "alloc")]
+    pub fn check(&self) -> Result<check::CheckReport, U> {
+        Ok(self.volume.check()?)
+    }
+}
+
+/// A [`DirectoryHandle`] whose errors are folded into `U`. See [`Trapped`].
+pub struct TrappedDirectory<'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    directory: DirectoryHandle<'a, C>,
+    _error: PhantomData<U>,
+}
+impl<'a, C, U> TrappedDirectory<'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+    U: From<Error<C::Error>>,
+{
+    // `&'a self`: `DirectoryHandle::file` returns a handle tied to its own
+    // elided receiver borrow (unlike `open_path` below, which is pinned to
+    // `'a` explicitly), so `self.directory` must be borrowed for the full
+    // `'a` here for that result to widen into `FileHandle<'a, C>`.
+    pub fn file(&'a self, name: &str, mode: Mode) -> Result<TrappedFile<'a, C, U>, U> {
+        let file = self.directory.file(name, mode)?;
+        Ok(TrappedFile {
+            file,
+            _error: PhantomData,
+        })
+    }
+    pub fn open_path(&self, path: &str, mode: Mode) -> Result<TrappedFile<'a, C, U>, U> {
+        let file = self.directory.open_path(path, mode)?;
+        Ok(TrappedFile {
+            file,
+            _error: PhantomData,
+        })
+    }
+    pub fn iter(&self) -> TrappedReadDir<'_, 'a, C, U> {
+        TrappedReadDir {
+            inner: self.directory.iter(),
+            _error: PhantomData,
+        }
+    }
+    pub fn walk(&self) -> TrappedWalk<'_, 'a, C, U> {
+        TrappedWalk {
+            inner: self.directory.walk(),
+            _error: PhantomData,
+        }
+    }
+    pub fn walk_with<F>(&self, visitor: F) -> Result<(), U>
+    where
+        F: FnMut(usize, &DirEntry) -> ControlFlow<()>,
+    {
+        Ok(self.directory.walk_with(visitor)?)
+    }
+}
+
+/// A [`FileHandle`] whose errors are folded into `U`. See [`Trapped`].
+pub struct TrappedFile<'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    file: FileHandle<'a, C>,
+    _error: PhantomData<U>,
+}
+impl<'a, C, U> TrappedFile<'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+    U: From<Error<C::Error>>,
+{
+    pub fn read(&mut self, buffer: &mut [u8]) -> Result<usize, U> {
+        Ok(self.file.read(buffer)?)
+    }
+    pub fn write(&mut self, data: &[u8]) -> Result<usize, U> {
+        Ok(self.file.write(data)?)
+    }
+    pub fn size(&self) -> u32 {
+        self.file.size()
+    }
+    pub fn seek(&mut self, pos: SeekFrom) -> Result<u32, U> {
+        Ok(self.file.seek(pos)?)
+    }
+    pub fn read_at(&mut self, pos: SeekFrom, buffer: &mut [u8]) -> Result<usize, U> {
+        Ok(self.file.read_at(pos, buffer)?)
+    }
+    pub fn write_at(&mut self, pos: SeekFrom, data: &[u8]) -> Result<usize, U> {
+        Ok(self.file.write_at(pos, data)?)
+    }
+}
+
+/// A [`ReadDir`] whose errors are folded into `U`. See [`Trapped`].
+pub struct TrappedReadDir<'b, 'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    inner: ReadDir<'b, 'a, C>,
+    _error: PhantomData<U>,
+}
+impl<'b, 'a, C, U> Iterator for TrappedReadDir<'b, 'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+    U: From<Error<C::Error>>,
+{
+    type Item = Result<DirEntry, U>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|item| item.map_err(U::from))
+    }
+}
+
+/// A [`Walk`] whose errors are folded into `U`. See [`Trapped`].
+pub struct TrappedWalk<'b, 'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+{
+    inner: Walk<'b, 'a, C>,
+    _error: PhantomData<U>,
+}
+impl<'b, 'a, C, U> Iterator for TrappedWalk<'b, 'a, C, U>
+where
+    C: ControllerTrait,
+    <C as ControllerTrait>::Error: core::fmt::Debug,
+    U: From<Error<C::Error>>,
+{
+    type Item = Result<(usize, DirEntry), U>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|item| item.map_err(U::from))
+    }
+}
+
+/// A read-only consistency checker for a mounted FAT volume.
+///
+/// `VolumeHandle::check()` walks the whole directory tree and follows each
+/// file's and subdirectory's FAT cluster chain, cross-referencing every
+/// visited cluster against a usage map sized to the volume's
+/// `cluster_count`. It never writes to the device; use it after an unclean
+/// power loss to find corruption before it causes a confusing failure
+/// somewhere else.
+#[cfg(feature = "alloc")]
+pub mod check {
+    use super::*;
+    extern crate alloc;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    /// A single anomaly found while checking a volume, together with the
+    /// cluster and the path that owns it (where applicable).
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Anomaly {
+        /// `cluster` is referenced by the chains of both `first_owner` and
+        /// `second_owner`.
+        CrossLinked {
+            cluster: u32,
+            first_owner: String,
+            second_owner: String,
+        },
+        /// `owner`'s chain revisits `cluster`, which it has already walked
+        /// through earlier in the same chain.
+        Cycle { cluster: u32, owner: String },
+        /// `owner`'s chain ended at `cluster` before its directory entry's
+        /// size was fully accounted for.
+        UnexpectedEnd { cluster: u32, owner: String },
+        /// `cluster` falls outside the volume's valid `[2, cluster_count +
+        /// 1]` range.
+        OutOfRange { cluster: u32, owner: String },
+        /// `count` clusters are marked allocated in the FAT but were never
+        /// reached while walking the directory tree.
+        Lost { count: u32 },
+    }
+
+    /// The result of [`VolumeHandle::check`].
+    #[derive(Debug, Clone, Default)]
+    pub struct CheckReport {
+        pub anomalies: Vec<Anomaly>,
+    }
+    impl CheckReport {
+        pub fn is_clean(&self) -> bool {
+            self.anomalies.is_empty()
+        }
+    }
+
+    struct CheckFrame<'a, C>
+    where
+        C: ControllerTrait,
+        <C as ControllerTrait>::Error: core::fmt::Debug,
+    {
+        directory: DirectoryHandle<'a, C>,
+        path: String,
+        index: usize,
+    }
+
+    impl<'a, C> VolumeHandle<'a, C>
+    where
+        C: ControllerTrait,
+        <C as ControllerTrait>::Error: core::fmt::Debug,
+    {
+        /// Validates this volume and returns a report of every anomaly
+        /// found. Does not mutate the device.
+        pub fn check(&self) -> Result<CheckReport, Error<C::Error>> {
+            let cluster_count = self.cluster_count()?;
+            let free_clusters_count = self.free_clusters_count()?;
+            let cluster_size_bytes = self.blocks_per_cluster()? as u32 * BLOCK_SIZE_BYTES;
+            let mut owners: Vec<Option<String>> = alloc::vec![None; cluster_count as usize];
+            let mut report = CheckReport::default();
+
+            let root = self.root()?;
+            self.check_directory(
+                &root,
+                "",
+                &mut owners,
+                cluster_count,
+                cluster_size_bytes,
+                &mut report,
+            )?;
+
+            let marked = owners.iter().filter(|owner| owner.is_some()).count() as u32;
+            let expected_allocated = cluster_count.saturating_sub(free_clusters_count);
+            if marked < expected_allocated {
+                report.anomalies.push(Anomaly::Lost {
+                    count: expected_allocated - marked,
+                });
+            }
+            Ok(report)
+        }
+
+        /// Walks `root` and every subdirectory beneath it, checking each
+        /// entry's cluster chain. Uses the same bounded explicit-stack
+        /// traversal as [`DirectoryHandle::walk`] rather than native
+        /// recursion, since a directory tree corrupted by a cycle or
+        /// cross-link would otherwise recurse without bound. A directory
+        /// whose starting cluster has already been descended into (an
+        /// ancestor, via a cycle, or another directory, via a cross-link)
+        /// is not descended into again -- `check_chain` already recorded
+        /// the anomaly against its owning path.
+        fn check_directory(
+            &self,
+            root: &DirectoryHandle<'a, C>,
+            root_path: &str,
+            owners: &mut [Option<String>],
+            cluster_count: u32,
+            cluster_size_bytes: u32,
+            report: &mut CheckReport,
+        ) -> Result<(), Error<C::Error>> {
+            let mut frames: [Option<CheckFrame<'a, C>>; MAX_WALK_DEPTH] =
+                core::array::from_fn(|_| None);
+            let mut root_index = 0usize;
+            let mut depth = 0usize;
+            let mut visited_dirs: Vec<u32> = Vec::new();
+
+            loop {
+                let current: &DirectoryHandle<'a, C> = if depth == 0 {
+                    root
+                } else {
+                    &frames[depth - 1].as_ref().unwrap().directory
+                };
+                let index = if depth == 0 {
+                    root_index
+                } else {
+                    frames[depth - 1].as_ref().unwrap().index
+                };
+
+                let entry = match dir_entry_at(current, index) {
+                    Err(e) => return Err(e),
+                    Ok(None) => {
+                        if depth == 0 {
+                            return Ok(());
+                        }
+                        frames[depth - 1] = None;
+                        depth -= 1;
+                        continue;
+                    }
+                    Ok(Some(entry)) => entry,
+                };
+
+                if depth == 0 {
+                    root_index += 1;
+                } else {
+                    frames[depth - 1].as_mut().unwrap().index += 1;
+                }
+
+                let name = ShortNameBuf::render(&entry);
+                if name.as_str() == "." || name.as_str() == ".." {
+                    continue;
+                }
+
+                let parent_path = if depth == 0 {
+                    root_path
+                } else {
+                    frames[depth - 1].as_ref().unwrap().path.as_str()
+                };
+                let mut owner = String::from(parent_path);
+                owner.push('/');
+                owner.push_str(name.as_str());
+
+                // A zero-length file conventionally has no cluster
+                // allocated (`cluster == 0`), not an out-of-range one;
+                // don't walk a chain that was never there.
+                if !(entry.cluster == 0 && entry.size == 0) {
+                    let expected_clusters = entry.size.div_ceil(cluster_size_bytes);
+                    self.check_chain(
+                        entry.cluster,
+                        &owner,
+                        expected_clusters,
+                        owners,
+                        cluster_count,
+                        report,
+                    )?;
+                }
+
+                if entry.attributes.is_directory() {
+                    if entry.cluster != 0 && visited_dirs.contains(&entry.cluster) {
+                        continue;
+                    }
+                    if depth >= MAX_WALK_DEPTH {
+                        return Err(Error::DirectoryDepthExceeded);
+                    }
+                    visited_dirs.push(entry.cluster);
+
+                    let this_depth = depth;
+                    let current: &DirectoryHandle<'a, C> = if this_depth == 0 {
+                        root
+                    } else {
+                        &frames[this_depth - 1].as_ref().unwrap().directory
+                    };
+                    let child = self.controller.open_dir(
+                        current.volume,
+                        current.directory.as_ref().unwrap(),
+                        name.as_str(),
+                    )?;
+                    frames[depth] = Some(CheckFrame {
+                        directory: child,
+                        path: owner,
+                        index: 0,
+                    });
+                    depth += 1;
+                }
+            }
         }
+
+        /// Follows a single file/directory's cluster chain, marking every
+        /// visited cluster as owned by `owner` and recording cross-links,
+        /// cycles, and out-of-range clusters as they're found. Stops
+        /// following the chain as soon as an anomaly is detected, since the
+        /// chain can no longer be trusted past that point. If the chain
+        /// ends cleanly but visited fewer than `expected_clusters`, records
+        /// an `UnexpectedEnd` for the last cluster reached.
+        fn check_chain(
+            &self,
+            start_cluster: u32,
+            owner: &str,
+            expected_clusters: u32,
+            owners: &mut [Option<String>],
+            cluster_count: u32,
+            report: &mut CheckReport,
+        ) -> Result<(), Error<C::Error>> {
+            let mut visited_this_chain: Vec<u32> = Vec::new();
+            let mut current = start_cluster;
+            loop {
+                if current < 2 || current > cluster_count + 1 {
+                    report.anomalies.push(Anomaly::OutOfRange {
+                        cluster: current,
+                        owner: String::from(owner),
+                    });
+                    return Ok(());
+                }
+                if visited_this_chain.contains(&current) {
+                    report.anomalies.push(Anomaly::Cycle {
+                        cluster: current,
+                        owner: String::from(owner),
+                    });
+                    return Ok(());
+                }
+                visited_this_chain.push(current);
+
+                let slot = &mut owners[(current - 2) as usize];
+                match slot {
+                    Some(existing) if existing != owner => {
+                        report.anomalies.push(Anomaly::CrossLinked {
+                            cluster: current,
+                            first_owner: existing.clone(),
+                            second_owner: String::from(owner),
+                        });
+                        return Ok(());
+                    }
+                    _ => *slot = Some(String::from(owner)),
+                }
+
+                match self.controller.next_cluster(self, current)? {
+                    Some(next) => current = next,
+                    None => break,
+                }
+            }
+
+            if (visited_this_chain.len() as u32) < expected_clusters {
+                report.anomalies.push(Anomaly::UnexpectedEnd {
+                    cluster: *visited_this_chain.last().unwrap(),
+                    owner: String::from(owner),
+                });
+            }
+            Ok(())
+        }
+    }
+}
+
+// Coverage in this module is limited to `handle.rs`'s pure helpers
+// (`path_components`, `resolve_seek`, `seek_crosses_cluster_bounds`, and so
+// on). Exercising `ReadDir`, `walk`/`walk_with`, `with_file`/`with_root`, or
+// `check` behaviorally needs a mock `ControllerTrait` impl, which in turn
+// needs to construct real `DirEntry`/`Directory`/`File`/`Volume` values --
+// those types live in this crate's top-level module (`use crate::{..}`
+// above), not in `handle.rs` itself, so a mock built here can't fabricate
+// them without guessing at fields this file never needed to know about.
+// Add the mock once those constructors (or test-only builders for them)
+// land alongside their definitions.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+
+    #[test]
+    fn path_components_splits_and_normalizes() {
+        assert!(path_components("/logs/2024/app.txt").eq(["logs", "2024", "app.txt"]));
+    }
+
+    #[test]
+    fn path_components_drops_empty_and_current_dir_segments() {
+        assert!(path_components("./logs//2024/./app.txt").eq(["logs", "2024", "app.txt"]));
+    }
+
+    #[test]
+    fn resolve_seek_start_current_end() {
+        assert_eq!(
+            resolve_seek::<Infallible>(5, 10, SeekFrom::Start(3)).unwrap(),
+            3
+        );
+        assert_eq!(
+            resolve_seek::<Infallible>(5, 10, SeekFrom::Current(2)).unwrap(),
+            7
+        );
+        assert_eq!(
+            resolve_seek::<Infallible>(5, 10, SeekFrom::Current(-5)).unwrap(),
+            0
+        );
+        assert_eq!(
+            resolve_seek::<Infallible>(5, 10, SeekFrom::End(-2)).unwrap(),
+            8
+        );
+    }
+
+    #[test]
+    fn resolve_seek_rejects_out_of_bounds() {
+        assert!(matches!(
+            resolve_seek::<Infallible>(5, 10, SeekFrom::Start(11)),
+            Err(Error::InvalidOffset)
+        ));
+        assert!(matches!(
+            resolve_seek::<Infallible>(5, 10, SeekFrom::Current(-6)),
+            Err(Error::InvalidOffset)
+        ));
+        assert!(matches!(
+            resolve_seek::<Infallible>(5, 10, SeekFrom::End(1)),
+            Err(Error::InvalidOffset)
+        ));
+    }
+
+    #[test]
+    fn seek_crosses_cluster_bounds_detects_backward_seek() {
+        // Current cluster spans bytes [512, 1024); seeking within it stays
+        // on the same cluster.
+        assert!(!seek_crosses_cluster_bounds(512, 512, 512));
+        assert!(!seek_crosses_cluster_bounds(512, 512, 600));
+        // Seeking to 0 (e.g. `SeekFrom::Start(0)` after reading past the
+        // first cluster) or anywhere before the cluster's start must
+        // trigger a reset back to `starting_cluster`.
+        assert!(seek_crosses_cluster_bounds(512, 512, 0));
+        assert!(seek_crosses_cluster_bounds(512, 512, 511));
+    }
+
+    #[test]
+    fn seek_crosses_cluster_bounds_detects_forward_seek() {
+        // Current cluster spans bytes [512, 1024); seeking at or past 1024
+        // (e.g. `SeekFrom::Start` jumping several clusters ahead) leaves the
+        // current cluster and must trigger a reset back to
+        // `starting_cluster` so the chain gets walked forward again.
+        assert!(seek_crosses_cluster_bounds(512, 512, 1024));
+        assert!(seek_crosses_cluster_bounds(512, 512, 2048));
+        assert!(!seek_crosses_cluster_bounds(512, 512, 1023));
     }
 }